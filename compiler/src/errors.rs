@@ -0,0 +1,124 @@
+//! Error types returned while enforcing constraints on a compiled Leo program.
+//!
+//! Each stage of constraint enforcement (values, expressions, functions, ...) gets its own
+//! error type so a caller can match on the stage that failed; lower-level errors are wrapped
+//! by the stage above them via `From` (a `FunctionError` arrives boxed).
+
+use leo_types::{Identifier, Span};
+use std::fmt;
+
+macro_rules! simple_error {
+    ($name:ident) => {
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            message: String,
+            span: Span,
+        }
+
+        impl $name {
+            pub fn new(message: String, span: Span) -> Self {
+                Self { message, span }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{} @ {}:{}", self.message, self.span.line, self.span.start)
+            }
+        }
+
+        impl std::error::Error for $name {}
+    };
+}
+
+simple_error!(AddressError);
+simple_error!(BooleanError);
+simple_error!(IntegerError);
+simple_error!(FieldError);
+simple_error!(GroupError);
+simple_error!(FunctionError);
+
+/// An error produced while enforcing constraints on an `Expression`.
+#[derive(Debug, Clone)]
+pub struct ExpressionError {
+    message: String,
+    span: Span,
+}
+
+impl ExpressionError {
+    fn new(message: String, span: Span) -> Self {
+        Self { message, span }
+    }
+
+    pub fn undefined_identifier(identifier: Identifier) -> Self {
+        let span = identifier.span.clone();
+        Self::new(format!("undefined identifier `{}`", identifier.name), span)
+    }
+
+    pub fn function_no_return(function: String, span: Span) -> Self {
+        Self::new(format!("function `{}` did not return a value", function), span)
+    }
+
+    /// `Self` was used outside of a circuit member function or static constructor.
+    pub fn self_keyword(span: Span) -> Self {
+        Self::new("`Self` is only valid inside a circuit function".to_string(), span)
+    }
+
+    /// An operation could not be enforced on its operands (e.g. a fallback that
+    /// only evaluates constants was given a non-constant operand, or a boolean
+    /// gadget failed to allocate).
+    pub fn cannot_enforce(operation: String, span: Span) -> Self {
+        Self::new(format!("cannot enforce `{}`", operation), span)
+    }
+
+    /// An operator was applied to operand types it doesn't support (e.g. unary `-`
+    /// on a `bool` or `address`).
+    pub fn incompatible_types(operation: String, span: Span) -> Self {
+        Self::new(format!("operation `{}` is not defined for these types", operation), span)
+    }
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} @ {}:{}", self.message, self.span.line, self.span.start)
+    }
+}
+
+impl std::error::Error for ExpressionError {}
+
+impl From<Box<FunctionError>> for ExpressionError {
+    fn from(error: Box<FunctionError>) -> Self {
+        let span = error.span.clone();
+        Self::new(error.to_string(), span)
+    }
+}
+
+impl From<AddressError> for ExpressionError {
+    fn from(error: AddressError) -> Self {
+        Self::new(error.to_string(), error.span.clone())
+    }
+}
+
+impl From<BooleanError> for ExpressionError {
+    fn from(error: BooleanError) -> Self {
+        Self::new(error.to_string(), error.span.clone())
+    }
+}
+
+impl From<IntegerError> for ExpressionError {
+    fn from(error: IntegerError) -> Self {
+        Self::new(error.to_string(), error.span.clone())
+    }
+}
+
+impl From<FieldError> for ExpressionError {
+    fn from(error: FieldError) -> Self {
+        Self::new(error.to_string(), error.span.clone())
+    }
+}
+
+impl From<GroupError> for ExpressionError {
+    fn from(error: GroupError) -> Self {
+        Self::new(error.to_string(), error.span.clone())
+    }
+}
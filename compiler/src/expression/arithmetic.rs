@@ -0,0 +1,106 @@
+//! Fallbacks for `Add`/`Sub`/`Mul`/`Div`/`Pow` used when at least one operand
+//! is not a compile-time constant, so the result can't be folded by
+//! `ConstrainedProgram::enforce_constant_binary_expression`.
+//!
+//! `Integer`/`FieldType` carry a host-side value and a `constant` flag, not a
+//! per-bit or per-limb witness, so there's no linear combination a
+//! non-constant operation could be tied to: computing `const_*` on an operand
+//! that isn't actually constant and handing back the result anyway would let
+//! a prover submit any witness for an unconstrained "result". A real gadget
+//! needs bit-decomposed (`Integer`) or limb-decomposed (`Field`) operands,
+//! which is out of scope here, so these fallbacks report that honestly
+//! instead of returning an unconstrained result. `GroupType` in this tree has
+//! no add/sub gadget, only `const_add`/`const_sub`, so `Group` operands are
+//! combined with those unconditionally, whether or not they are constant.
+
+use crate::errors::ExpressionError;
+use crate::value::{ConstrainedValue, GroupType};
+use leo_types::Span;
+use snarkos_models::curves::{Field, PrimeField};
+
+macro_rules! enforce_arithmetic {
+    ($fn_name:ident, $const_method:ident, $operator:expr) => {
+        pub fn $fn_name<F: Field + PrimeField, G: GroupType<F>, CS>(
+            _cs: &mut CS,
+            resolved_left: ConstrainedValue<F, G>,
+            resolved_right: ConstrainedValue<F, G>,
+            span: Span,
+        ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+            if !resolved_left.is_constant() || !resolved_right.is_constant() {
+                return Err(ExpressionError::cannot_enforce(
+                    format!("{} on a non-constant operand", $operator),
+                    span,
+                ));
+            }
+
+            match (resolved_left, resolved_right) {
+                (ConstrainedValue::Integer(left), ConstrainedValue::Integer(right)) => {
+                    Ok(ConstrainedValue::Integer(left.$const_method(&right, &span)?))
+                }
+                (ConstrainedValue::Field(left), ConstrainedValue::Field(right)) => {
+                    Ok(ConstrainedValue::Field(left.$const_method(&right, &span)?))
+                }
+                _ => Err(ExpressionError::incompatible_types($operator.to_string(), span)),
+            }
+        }
+    };
+}
+
+macro_rules! enforce_arithmetic_with_group {
+    ($fn_name:ident, $const_method:ident, $operator:expr) => {
+        pub fn $fn_name<F: Field + PrimeField, G: GroupType<F>, CS>(
+            _cs: &mut CS,
+            resolved_left: ConstrainedValue<F, G>,
+            resolved_right: ConstrainedValue<F, G>,
+            span: Span,
+        ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+            if let (ConstrainedValue::Group(left), ConstrainedValue::Group(right)) = (&resolved_left, &resolved_right) {
+                return Ok(ConstrainedValue::Group(left.$const_method(right, &span)?));
+            }
+
+            if !resolved_left.is_constant() || !resolved_right.is_constant() {
+                return Err(ExpressionError::cannot_enforce(
+                    format!("{} on a non-constant operand", $operator),
+                    span,
+                ));
+            }
+
+            match (resolved_left, resolved_right) {
+                (ConstrainedValue::Integer(left), ConstrainedValue::Integer(right)) => {
+                    Ok(ConstrainedValue::Integer(left.$const_method(&right, &span)?))
+                }
+                (ConstrainedValue::Field(left), ConstrainedValue::Field(right)) => {
+                    Ok(ConstrainedValue::Field(left.$const_method(&right, &span)?))
+                }
+                _ => Err(ExpressionError::incompatible_types($operator.to_string(), span)),
+            }
+        }
+    };
+}
+
+enforce_arithmetic_with_group!(enforce_add_expression, const_add, "+");
+enforce_arithmetic_with_group!(enforce_sub_expression, const_sub, "-");
+enforce_arithmetic!(enforce_mul_expression, const_mul, "*");
+enforce_arithmetic!(enforce_div_expression, const_div, "/");
+enforce_arithmetic!(enforce_pow_expression, const_pow, "**");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::span;
+    use crate::value::Integer;
+
+    #[test]
+    fn add_on_a_non_constant_operand_is_an_error() {
+        let allocated = ConstrainedValue::<crate::test_support::MockField, crate::test_support::MockGroup>::Integer(Integer {
+            type_: "u8".to_string(),
+            value: 1,
+            constant: false,
+        });
+        let constant = ConstrainedValue::Integer(Integer::new_constant("u8", "1".to_string(), span()).unwrap());
+
+        let result = enforce_add_expression(&mut (), allocated, constant, span());
+
+        assert!(result.is_err());
+    }
+}
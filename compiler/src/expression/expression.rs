@@ -19,18 +19,169 @@ use snarkos_models::{
     gadgets::r1cs::ConstraintSystem,
 };
 
+/// The arithmetic, boolean, or relational operator a binary expression applies.
+/// Used to dispatch constant-folding so it can live in one place instead of
+/// being duplicated across every `Expression::*` match arm.
+#[derive(Clone, Copy, Debug)]
+enum BinaryOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    And,
+    Or,
+    Eq,
+    Ge,
+    Gt,
+    Le,
+    Lt,
+}
+
 impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    /// Returns `true` if `value` is a boolean, integer, field, or group that is
+    /// already known at compile time, i.e. no variable allocated in the
+    /// `ConstraintSystem` backs it. Every other variant (addresses included)
+    /// returns `false`, so it is never folded here.
+    fn is_constant(value: &ConstrainedValue<F, G>) -> bool {
+        match value {
+            ConstrainedValue::Boolean(boolean) => boolean.is_constant(),
+            ConstrainedValue::Integer(integer) => integer.is_constant(),
+            ConstrainedValue::Field(field) => field.is_constant(),
+            ConstrainedValue::Group(group) => group.is_constant(),
+            _ => false,
+        }
+    }
+
+    /// If both `resolved_left` and `resolved_right` are compile-time
+    /// constants, evaluate `operator` directly in the host and return the
+    /// folded constant. Returns `Ok(None)` when either operand still depends
+    /// on an allocated variable, so the caller falls back to allocating the
+    /// usual gadget.
+    ///
+    /// Constant division by zero and constant exponentiation overflow are
+    /// caught here and surfaced as `ExpressionError`s instead of being passed
+    /// through to a gadget that can't represent them.
+    fn enforce_constant_binary_expression(
+        operator: BinaryOperator,
+        resolved_left: &ConstrainedValue<F, G>,
+        resolved_right: &ConstrainedValue<F, G>,
+        span: &Span,
+    ) -> Result<Option<ConstrainedValue<F, G>>, ExpressionError> {
+        if !(Self::is_constant(resolved_left) && Self::is_constant(resolved_right)) {
+            return Ok(None);
+        }
+
+        let folded = match (operator, resolved_left, resolved_right) {
+            (BinaryOperator::Add, ConstrainedValue::Integer(left), ConstrainedValue::Integer(right)) => {
+                ConstrainedValue::Integer(left.const_add(right, span)?)
+            }
+            (BinaryOperator::Add, ConstrainedValue::Field(left), ConstrainedValue::Field(right)) => {
+                ConstrainedValue::Field(left.const_add(right, span)?)
+            }
+            (BinaryOperator::Add, ConstrainedValue::Group(left), ConstrainedValue::Group(right)) => {
+                ConstrainedValue::Group(left.const_add(right, span)?)
+            }
+
+            (BinaryOperator::Sub, ConstrainedValue::Integer(left), ConstrainedValue::Integer(right)) => {
+                ConstrainedValue::Integer(left.const_sub(right, span)?)
+            }
+            (BinaryOperator::Sub, ConstrainedValue::Field(left), ConstrainedValue::Field(right)) => {
+                ConstrainedValue::Field(left.const_sub(right, span)?)
+            }
+            (BinaryOperator::Sub, ConstrainedValue::Group(left), ConstrainedValue::Group(right)) => {
+                ConstrainedValue::Group(left.const_sub(right, span)?)
+            }
+
+            (BinaryOperator::Mul, ConstrainedValue::Integer(left), ConstrainedValue::Integer(right)) => {
+                ConstrainedValue::Integer(left.const_mul(right, span)?)
+            }
+            (BinaryOperator::Mul, ConstrainedValue::Field(left), ConstrainedValue::Field(right)) => {
+                ConstrainedValue::Field(left.const_mul(right, span)?)
+            }
+
+            (BinaryOperator::Div, ConstrainedValue::Integer(left), ConstrainedValue::Integer(right)) => {
+                ConstrainedValue::Integer(left.const_div(right, span)?)
+            }
+            (BinaryOperator::Div, ConstrainedValue::Field(left), ConstrainedValue::Field(right)) => {
+                ConstrainedValue::Field(left.const_div(right, span)?)
+            }
+
+            (BinaryOperator::Pow, ConstrainedValue::Integer(left), ConstrainedValue::Integer(right)) => {
+                ConstrainedValue::Integer(left.const_pow(right, span)?)
+            }
+            (BinaryOperator::Pow, ConstrainedValue::Field(left), ConstrainedValue::Field(right)) => {
+                ConstrainedValue::Field(left.const_pow(right, span)?)
+            }
+
+            (BinaryOperator::And, ConstrainedValue::Boolean(left), ConstrainedValue::Boolean(right)) => {
+                let result = left
+                    .get_value()
+                    .zip(right.get_value())
+                    .map(|(left, right)| left && right)
+                    .ok_or_else(|| ExpressionError::cannot_enforce("&&".to_string(), span.clone()))?;
+
+                ConstrainedValue::Boolean(new_bool_constant(result, span.clone())?)
+            }
+            (BinaryOperator::Or, ConstrainedValue::Boolean(left), ConstrainedValue::Boolean(right)) => {
+                let result = left
+                    .get_value()
+                    .zip(right.get_value())
+                    .map(|(left, right)| left || right)
+                    .ok_or_else(|| ExpressionError::cannot_enforce("||".to_string(), span.clone()))?;
+
+                ConstrainedValue::Boolean(new_bool_constant(result, span.clone())?)
+            }
+
+            (BinaryOperator::Eq, left, right) => {
+                ConstrainedValue::Boolean(new_bool_constant(left.const_eq(right, span)?, span.clone())?)
+            }
+            (BinaryOperator::Ge, left, right) => {
+                ConstrainedValue::Boolean(new_bool_constant(left.const_ge(right, span)?, span.clone())?)
+            }
+            (BinaryOperator::Gt, left, right) => {
+                ConstrainedValue::Boolean(new_bool_constant(left.const_gt(right, span)?, span.clone())?)
+            }
+            (BinaryOperator::Le, left, right) => {
+                ConstrainedValue::Boolean(new_bool_constant(left.const_le(right, span)?, span.clone())?)
+            }
+            (BinaryOperator::Lt, left, right) => {
+                ConstrainedValue::Boolean(new_bool_constant(left.const_lt(right, span)?, span.clone())?)
+            }
+
+            // Mismatched or unsupported operand types still need to go through
+            // the usual gadget path so the existing type errors are reported.
+            _ => return Ok(None),
+        };
+
+        Ok(Some(folded))
+    }
+
+    /// Negate a compile-time constant operand of unary `-` directly in the
+    /// host, mirroring how `Expression::Field`/`Expression::Group` build
+    /// their constants so that a literal like `-5field` never allocates a
+    /// subtraction gadget.
+    fn enforce_constant_negate(resolved: ConstrainedValue<F, G>, span: Span) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+        match resolved {
+            ConstrainedValue::Integer(integer) => Ok(ConstrainedValue::Integer(integer.const_negate(&span)?)),
+            ConstrainedValue::Field(field) => Ok(ConstrainedValue::Field(field.const_negate(&span)?)),
+            ConstrainedValue::Group(group) => Ok(ConstrainedValue::Group(group.const_negate(&span)?)),
+            _ => Err(ExpressionError::incompatible_types("-".to_string(), span)),
+        }
+    }
+
     /// Enforce a variable expression by getting the resolved value
     pub(crate) fn evaluate_identifier(
         &mut self,
         file_scope: String,
         function_scope: String,
+        self_type: Option<&Identifier>,
         expected_types: &Vec<Type>,
         unresolved_identifier: Identifier,
     ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
         // Evaluate the identifier name in the current function scope
         let variable_name = new_scope(function_scope.clone(), unresolved_identifier.to_string());
-        let identifier_name = new_scope(file_scope, unresolved_identifier.to_string());
+        let identifier_name = new_scope(file_scope.clone(), unresolved_identifier.to_string());
 
         let mut result_value = if let Some(value) = self.get(&variable_name) {
             // Reassigning variable to another variable
@@ -46,6 +197,13 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             let address = Address::new(unresolved_identifier.name, unresolved_identifier.span)?;
 
             return Ok(ConstrainedValue::Address(address));
+        } else if unresolved_identifier.name.eq("Self") {
+            // Resolve `Self` to the circuit definition enclosing the current function
+            let circuit_identifier = self_type
+                .ok_or_else(|| ExpressionError::self_keyword(unresolved_identifier.span.clone()))?
+                .clone();
+
+            return self.evaluate_identifier(file_scope, function_scope, None, expected_types, circuit_identifier);
         } else {
             return Err(ExpressionError::undefined_identifier(unresolved_identifier));
         };
@@ -60,6 +218,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         cs: &mut CS,
         file_scope: String,
         function_scope: String,
+        self_type: Option<&Identifier>,
         expected_types: &Vec<Type>,
         function: Box<Expression>,
         arguments: Vec<Expression>,
@@ -69,6 +228,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             cs,
             file_scope.clone(),
             function_scope.clone(),
+            self_type,
             expected_types,
             *function.clone(),
         )?;
@@ -121,11 +281,12 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         cs: &mut CS,
         file_scope: String,
         function_scope: String,
+        self_type: Option<&Identifier>,
         expected_types: &Vec<Type>,
         expression: Expression,
         span: Span,
     ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
-        let mut branch = self.enforce_expression(cs, file_scope, function_scope, expected_types, expression)?;
+        let mut branch = self.enforce_expression(cs, file_scope, function_scope, self_type, expected_types, expression)?;
 
         branch.get_inner_mut();
         branch.resolve_type(expected_types, span)?;
@@ -138,6 +299,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         cs: &mut CS,
         file_scope: String,
         function_scope: String,
+        self_type: Option<&Identifier>,
         expected_types: &Vec<Type>,
         left: Expression,
         right: Expression,
@@ -147,22 +309,84 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             cs,
             file_scope.clone(),
             function_scope.clone(),
+            self_type,
             expected_types,
             left,
             span.clone(),
         )?;
-        let mut resolved_right = self.enforce_expression_value(
+        // `file_scope`/`function_scope` aren't read again after this call, so the right
+        // operand takes ownership instead of cloning a second time.
+        let mut resolved_right =
+            self.enforce_expression_value(cs, file_scope, function_scope, self_type, expected_types, right, span.clone())?;
+
+        resolved_left.resolve_types(&mut resolved_right, expected_types, span)?;
+
+        Ok((resolved_left, resolved_right))
+    }
+
+    /// Enforce `&&`/`||` with short-circuit semantics: the right operand is
+    /// only enforced (and only allocates constraints) if the left operand
+    /// doesn't already determine the result. `short_circuit_value` is the
+    /// value that short-circuits the operator (`false` for `&&`, `true` for
+    /// `||`). This can't reuse `enforce_binary_expression` since that helper
+    /// always resolves both operands before returning.
+    fn enforce_short_circuit_boolean_expression<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        file_scope: String,
+        function_scope: String,
+        self_type: Option<&Identifier>,
+        expected_types: &Vec<Type>,
+        left: Expression,
+        right: Expression,
+        span: Span,
+        short_circuit_value: bool,
+    ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+        let operator = if short_circuit_value { BinaryOperator::Or } else { BinaryOperator::And };
+
+        let mut resolved_left = self.enforce_expression_value(
             cs,
             file_scope.clone(),
             function_scope.clone(),
+            self_type,
+            expected_types,
+            left,
+            span.clone(),
+        )?;
+
+        if let ConstrainedValue::Boolean(left_boolean) = &resolved_left {
+            if left_boolean.is_constant() {
+                let left_value = left_boolean
+                    .get_value()
+                    .ok_or_else(|| ExpressionError::cannot_enforce(format!("{:?}", operator), span.clone()))?;
+
+                if left_value == short_circuit_value {
+                    return Ok(ConstrainedValue::Boolean(new_bool_constant(short_circuit_value, span)?));
+                }
+            }
+        }
+
+        let mut resolved_right = self.enforce_expression_value(
+            cs,
+            file_scope,
+            function_scope,
+            self_type,
             expected_types,
             right,
             span.clone(),
         )?;
 
-        resolved_left.resolve_types(&mut resolved_right, expected_types, span)?;
+        resolved_left.resolve_types(&mut resolved_right, expected_types, span.clone())?;
 
-        Ok((resolved_left, resolved_right))
+        if let Some(folded) = Self::enforce_constant_binary_expression(operator, &resolved_left, &resolved_right, &span)? {
+            return Ok(folded);
+        }
+
+        if short_circuit_value {
+            Ok(enforce_or(cs, resolved_left, resolved_right, span)?)
+        } else {
+            Ok(enforce_and(cs, resolved_left, resolved_right, span)?)
+        }
     }
 
     pub(crate) fn enforce_expression<CS: ConstraintSystem<F>>(
@@ -170,13 +394,14 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         cs: &mut CS,
         file_scope: String,
         function_scope: String,
+        self_type: Option<&Identifier>,
         expected_types: &Vec<Type>,
         expression: Expression,
     ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
         match expression {
             // Variables
             Expression::Identifier(unresolved_variable) => {
-                self.evaluate_identifier(file_scope, function_scope, expected_types, unresolved_variable)
+                self.evaluate_identifier(file_scope, function_scope, self_type, expected_types, unresolved_variable)
             }
 
             // Values
@@ -195,12 +420,19 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     cs,
                     file_scope.clone(),
                     function_scope.clone(),
+                    self_type,
                     expected_types,
                     *left,
                     *right,
                     span.clone(),
                 )?;
 
+                if let Some(folded) =
+                    Self::enforce_constant_binary_expression(BinaryOperator::Add, &resolved_left, &resolved_right, &span)?
+                {
+                    return Ok(folded);
+                }
+
                 enforce_add_expression(cs, resolved_left, resolved_right, span)
             }
             Expression::Sub(left, right, span) => {
@@ -208,12 +440,19 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     cs,
                     file_scope.clone(),
                     function_scope.clone(),
+                    self_type,
                     expected_types,
                     *left,
                     *right,
                     span.clone(),
                 )?;
 
+                if let Some(folded) =
+                    Self::enforce_constant_binary_expression(BinaryOperator::Sub, &resolved_left, &resolved_right, &span)?
+                {
+                    return Ok(folded);
+                }
+
                 enforce_sub_expression(cs, resolved_left, resolved_right, span)
             }
             Expression::Mul(left, right, span) => {
@@ -221,12 +460,19 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     cs,
                     file_scope.clone(),
                     function_scope.clone(),
+                    self_type,
                     expected_types,
                     *left,
                     *right,
                     span.clone(),
                 )?;
 
+                if let Some(folded) =
+                    Self::enforce_constant_binary_expression(BinaryOperator::Mul, &resolved_left, &resolved_right, &span)?
+                {
+                    return Ok(folded);
+                }
+
                 enforce_mul_expression(cs, resolved_left, resolved_right, span)
             }
             Expression::Div(left, right, span) => {
@@ -234,12 +480,19 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     cs,
                     file_scope.clone(),
                     function_scope.clone(),
+                    self_type,
                     expected_types,
                     *left,
                     *right,
                     span.clone(),
                 )?;
 
+                if let Some(folded) =
+                    Self::enforce_constant_binary_expression(BinaryOperator::Div, &resolved_left, &resolved_right, &span)?
+                {
+                    return Ok(folded);
+                }
+
                 enforce_div_expression(cs, resolved_left, resolved_right, span)
             }
             Expression::Pow(left, right, span) => {
@@ -247,57 +500,102 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     cs,
                     file_scope.clone(),
                     function_scope.clone(),
+                    self_type,
                     expected_types,
                     *left,
                     *right,
                     span.clone(),
                 )?;
 
+                if let Some(folded) =
+                    Self::enforce_constant_binary_expression(BinaryOperator::Pow, &resolved_left, &resolved_right, &span)?
+                {
+                    return Ok(folded);
+                }
+
                 enforce_pow_expression(cs, resolved_left, resolved_right, span)
             }
 
-            // Boolean operations
-            Expression::Not(expression, span) => Ok(evaluate_not(
-                self.enforce_expression(cs, file_scope, function_scope, expected_types, *expression)?,
-                span,
-            )?),
-            Expression::Or(left, right, span) => {
-                let (resolved_left, resolved_right) = self.enforce_binary_expression(
+            // Unary operations
+            Expression::Negate(expression, span) => {
+                let resolved = self.enforce_expression_value(
                     cs,
-                    file_scope.clone(),
-                    function_scope.clone(),
+                    file_scope,
+                    function_scope,
+                    self_type,
                     expected_types,
-                    *left,
-                    *right,
+                    *expression,
                     span.clone(),
                 )?;
 
-                Ok(enforce_or(cs, resolved_left, resolved_right, span)?)
+                if Self::is_constant(&resolved) {
+                    return Self::enforce_constant_negate(resolved, span);
+                }
+
+                match resolved {
+                    ConstrainedValue::Integer(integer) => Ok(ConstrainedValue::Integer(integer.negate(cs, span)?)),
+                    ConstrainedValue::Field(field) => Ok(ConstrainedValue::Field(field.negate(cs, span)?)),
+                    ConstrainedValue::Group(group) => Ok(ConstrainedValue::Group(group.negate(cs, span)?)),
+                    _ => Err(ExpressionError::incompatible_types("-".to_string(), span)),
+                }
             }
-            Expression::And(left, right, span) => {
-                let (resolved_left, resolved_right) = self.enforce_binary_expression(
-                    cs,
-                    file_scope.clone(),
-                    function_scope.clone(),
-                    expected_types,
-                    *left,
-                    *right,
-                    span.clone(),
-                )?;
 
-                Ok(enforce_and(cs, resolved_left, resolved_right, span)?)
+            // Boolean operations
+            Expression::Not(expression, span) => {
+                let resolved = self.enforce_expression(cs, file_scope, function_scope, self_type, expected_types, *expression)?;
+
+                if let ConstrainedValue::Boolean(boolean) = &resolved {
+                    if boolean.is_constant() {
+                        let value = boolean
+                            .get_value()
+                            .ok_or_else(|| ExpressionError::cannot_enforce("!".to_string(), span.clone()))?;
+
+                        return Ok(ConstrainedValue::Boolean(new_bool_constant(!value, span)?));
+                    }
+                }
+
+                Ok(evaluate_not(resolved, span)?)
             }
+            Expression::Or(left, right, span) => self.enforce_short_circuit_boolean_expression(
+                cs,
+                file_scope,
+                function_scope,
+                self_type,
+                expected_types,
+                *left,
+                *right,
+                span,
+                true,
+            ),
+            Expression::And(left, right, span) => self.enforce_short_circuit_boolean_expression(
+                cs,
+                file_scope,
+                function_scope,
+                self_type,
+                expected_types,
+                *left,
+                *right,
+                span,
+                false,
+            ),
             Expression::Eq(left, right, span) => {
                 let (resolved_left, resolved_right) = self.enforce_binary_expression(
                     cs,
                     file_scope.clone(),
                     function_scope.clone(),
+                    self_type,
                     &vec![],
                     *left,
                     *right,
                     span.clone(),
                 )?;
 
+                if let Some(folded) =
+                    Self::enforce_constant_binary_expression(BinaryOperator::Eq, &resolved_left, &resolved_right, &span)?
+                {
+                    return Ok(folded);
+                }
+
                 Ok(evaluate_eq_expression(cs, resolved_left, resolved_right, span)?)
             }
             Expression::Ge(left, right, span) => {
@@ -305,12 +603,19 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     cs,
                     file_scope.clone(),
                     function_scope.clone(),
+                    self_type,
                     &vec![],
                     *left,
                     *right,
                     span.clone(),
                 )?;
 
+                if let Some(folded) =
+                    Self::enforce_constant_binary_expression(BinaryOperator::Ge, &resolved_left, &resolved_right, &span)?
+                {
+                    return Ok(folded);
+                }
+
                 Ok(evaluate_ge_expression(cs, resolved_left, resolved_right, span)?)
             }
             Expression::Gt(left, right, span) => {
@@ -318,12 +623,19 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     cs,
                     file_scope.clone(),
                     function_scope.clone(),
+                    self_type,
                     &vec![],
                     *left,
                     *right,
                     span.clone(),
                 )?;
 
+                if let Some(folded) =
+                    Self::enforce_constant_binary_expression(BinaryOperator::Gt, &resolved_left, &resolved_right, &span)?
+                {
+                    return Ok(folded);
+                }
+
                 Ok(evaluate_gt_expression(cs, resolved_left, resolved_right, span)?)
             }
             Expression::Le(left, right, span) => {
@@ -331,12 +643,19 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     cs,
                     file_scope.clone(),
                     function_scope.clone(),
+                    self_type,
                     &vec![],
                     *left,
                     *right,
                     span.clone(),
                 )?;
 
+                if let Some(folded) =
+                    Self::enforce_constant_binary_expression(BinaryOperator::Le, &resolved_left, &resolved_right, &span)?
+                {
+                    return Ok(folded);
+                }
+
                 Ok(evaluate_le_expression(cs, resolved_left, resolved_right, span)?)
             }
             Expression::Lt(left, right, span) => {
@@ -344,12 +663,19 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     cs,
                     file_scope.clone(),
                     function_scope.clone(),
+                    self_type,
                     &vec![],
                     *left,
                     *right,
                     span.clone(),
                 )?;
 
+                if let Some(folded) =
+                    Self::enforce_constant_binary_expression(BinaryOperator::Lt, &resolved_left, &resolved_right, &span)?
+                {
+                    return Ok(folded);
+                }
+
                 Ok(evaluate_lt_expression(cs, resolved_left, resolved_right, span)?)
             }
 
@@ -381,6 +707,15 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
 
             // Circuits
             Expression::Circuit(circuit_name, members, span) => {
+                // `Self { ... }` constructs the circuit enclosing the current function
+                let circuit_name = if circuit_name.name.eq("Self") {
+                    self_type
+                        .ok_or_else(|| ExpressionError::self_keyword(circuit_name.span.clone()))?
+                        .clone()
+                } else {
+                    circuit_name
+                };
+
                 self.enforce_circuit_expression(cs, file_scope, function_scope, circuit_name, members, span)
             }
             Expression::CircuitMemberAccess(circuit_variable, circuit_member, span) => self
@@ -409,6 +744,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 cs,
                 file_scope,
                 function_scope,
+                self_type,
                 expected_types,
                 function,
                 arguments,
@@ -417,3 +753,62 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{identifier, int_value, span, MockCS, MockField, MockGroup};
+
+    #[test]
+    fn self_resolves_to_the_enclosing_circuit() {
+        let mut program: ConstrainedProgram<MockField, MockGroup> = ConstrainedProgram::new();
+        let circuit = identifier("Pedersen");
+
+        program.set(new_scope("file".to_string(), circuit.to_string()), int_value("1"));
+
+        let result = program
+            .evaluate_identifier("file".to_string(), "func".to_string(), Some(&circuit), &vec![], identifier("Self"))
+            .unwrap();
+
+        match result {
+            ConstrainedValue::Integer(integer) => assert_eq!(integer.value, 1),
+            _ => panic!("expected an integer value"),
+        }
+    }
+
+    #[test]
+    fn self_outside_a_circuit_function_is_an_error() {
+        let mut program: ConstrainedProgram<MockField, MockGroup> = ConstrainedProgram::new();
+
+        let result = program.evaluate_identifier("file".to_string(), "func".to_string(), None, &vec![], identifier("Self"));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn and_short_circuits_without_enforcing_the_right_operand() {
+        let mut program: ConstrainedProgram<MockField, MockGroup> = ConstrainedProgram::new();
+        let mut cs = MockCS;
+
+        // If the right operand were enforced, resolving this undefined
+        // identifier would return an error instead of `false`.
+        let result = program
+            .enforce_short_circuit_boolean_expression(
+                &mut cs,
+                "file".to_string(),
+                "func".to_string(),
+                None,
+                &vec![],
+                Expression::Boolean(false, span()),
+                Expression::Identifier(identifier("undefined")),
+                span(),
+                false,
+            )
+            .unwrap();
+
+        match result {
+            ConstrainedValue::Boolean(boolean) => assert_eq!(boolean.get_value(), Some(false)),
+            _ => panic!("expected a boolean value"),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Gadget fallbacks for unary `!`, `&&`, and `||` used when an operand isn't a
+//! compile-time constant boolean, so the result can't be folded by
+//! `ConstrainedProgram::enforce_constant_binary_expression`.
+
+use crate::errors::ExpressionError;
+use crate::value::{ConstrainedValue, GroupType};
+use leo_types::Span;
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{r1cs::ConstraintSystem, utilities::boolean::Boolean},
+};
+
+pub fn evaluate_not<F: Field + PrimeField, G: GroupType<F>>(
+    resolved: ConstrainedValue<F, G>,
+    span: Span,
+) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+    match resolved {
+        ConstrainedValue::Boolean(boolean) => Ok(ConstrainedValue::Boolean(boolean.not())),
+        _ => Err(ExpressionError::incompatible_types("!".to_string(), span)),
+    }
+}
+
+fn as_booleans<F: Field + PrimeField, G: GroupType<F>>(
+    resolved_left: ConstrainedValue<F, G>,
+    resolved_right: ConstrainedValue<F, G>,
+    operator: &str,
+    span: &Span,
+) -> Result<(Boolean, Boolean), ExpressionError> {
+    match (resolved_left, resolved_right) {
+        (ConstrainedValue::Boolean(left), ConstrainedValue::Boolean(right)) => Ok((left, right)),
+        _ => Err(ExpressionError::incompatible_types(operator.to_string(), span.clone())),
+    }
+}
+
+/// Allocates a real boolean-AND gadget constraining the output to the inputs
+/// (`Boolean::and`), rather than folding `get_value()`s on the host: the
+/// latter would both reject a valid setup-phase witness (no value assigned
+/// yet) and, when it did return a value, hand back a `Boolean` with no
+/// constraint tying it to `left`/`right` at all.
+pub fn enforce_and<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    resolved_left: ConstrainedValue<F, G>,
+    resolved_right: ConstrainedValue<F, G>,
+    span: Span,
+) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+    let (left, right) = as_booleans(resolved_left, resolved_right, "&&", &span)?;
+
+    let result = Boolean::and(cs, &left, &right).map_err(|_| ExpressionError::cannot_enforce("&&".to_string(), span))?;
+
+    Ok(ConstrainedValue::Boolean(result))
+}
+
+/// `a || b` is folded to `!(!a && !b)` (De Morgan's law) so the only boolean
+/// gadget this module depends on is `Boolean::and`; `Boolean::not` just flips
+/// which side of the witness is treated as true/false and needs no `cs`.
+pub fn enforce_or<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    resolved_left: ConstrainedValue<F, G>,
+    resolved_right: ConstrainedValue<F, G>,
+    span: Span,
+) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+    let (left, right) = as_booleans(resolved_left, resolved_right, "||", &span)?;
+
+    let result = Boolean::and(cs, &left.not(), &right.not()).map_err(|_| ExpressionError::cannot_enforce("||".to_string(), span))?;
+
+    Ok(ConstrainedValue::Boolean(result.not()))
+}
@@ -0,0 +1,65 @@
+//! Fallbacks for `==`, `>=`, `>`, `<=`, `<` used when an operand isn't a
+//! compile-time constant, so the comparison can't be folded by
+//! `ConstrainedProgram::enforce_constant_binary_expression`.
+//!
+//! `Integer`/`FieldType` carry a host-side value and a `constant` flag, not a
+//! per-bit witness, so there's no linear combination a non-constant comparison
+//! could be tied to: evaluating `const_*` on an operand that isn't actually
+//! constant and handing back the result anyway would accept a proof for any
+//! witness, independent of its real value. A full comparison gadget needs
+//! bit-decomposed operands, which is out of scope here, so these fallbacks
+//! report that honestly instead of returning an unconstrained result.
+
+use crate::errors::ExpressionError;
+use crate::value::{boolean::input::new_bool_constant, ConstrainedValue, GroupType};
+use leo_types::Span;
+use snarkos_models::curves::{Field, PrimeField};
+
+macro_rules! evaluate_relational {
+    ($fn_name:ident, $const_method:ident, $operator:expr) => {
+        pub fn $fn_name<F: Field + PrimeField, G: GroupType<F>, CS>(
+            _cs: &mut CS,
+            resolved_left: ConstrainedValue<F, G>,
+            resolved_right: ConstrainedValue<F, G>,
+            span: Span,
+        ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+            if !resolved_left.is_constant() || !resolved_right.is_constant() {
+                return Err(ExpressionError::cannot_enforce(
+                    format!("{} on a non-constant operand", $operator),
+                    span,
+                ));
+            }
+
+            let result = resolved_left.$const_method(&resolved_right, &span)?;
+
+            Ok(ConstrainedValue::Boolean(new_bool_constant(result, span)?))
+        }
+    };
+}
+
+evaluate_relational!(evaluate_eq_expression, const_eq, "==");
+evaluate_relational!(evaluate_ge_expression, const_ge, ">=");
+evaluate_relational!(evaluate_gt_expression, const_gt, ">");
+evaluate_relational!(evaluate_le_expression, const_le, "<=");
+evaluate_relational!(evaluate_lt_expression, const_lt, "<");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::span;
+    use crate::value::Integer;
+
+    #[test]
+    fn eq_on_a_non_constant_operand_is_an_error() {
+        let allocated = ConstrainedValue::<crate::test_support::MockField, crate::test_support::MockGroup>::Integer(Integer {
+            type_: "u8".to_string(),
+            value: 1,
+            constant: false,
+        });
+        let constant = ConstrainedValue::Integer(Integer::new_constant("u8", "1".to_string(), span()).unwrap());
+
+        let result = evaluate_eq_expression(&mut (), allocated, constant, span());
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,19 @@
+//! Enforces constraints on a parsed Leo program against a `ConstraintSystem`.
+
+pub mod errors;
+pub mod expression;
+pub mod program;
+pub mod value;
+
+#[cfg(test)]
+pub(crate) mod test_support;
+
+// `expression.rs` imports the binary-operator gadget fallbacks as
+// `crate::{arithmetic, logical, relational}` rather than nesting them under
+// `crate::expression`, matching how `expression/mod.rs` re-exports their
+// contents with `pub use self::arithmetic::*` and friends.
+pub use expression::arithmetic;
+pub use expression::logical;
+pub use expression::relational;
+
+pub use value::{Address, FieldType, GroupType, Integer};
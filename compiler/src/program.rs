@@ -0,0 +1,80 @@
+//! The `ConstrainedProgram` struct tracks every resolved variable, function, and
+//! circuit definition visible while a Leo program is being enforced, keyed by a
+//! flat scope string built from `new_scope`.
+
+use crate::value::{ConstrainedValue, GroupType};
+use snarkos_models::curves::{Field, PrimeField};
+use std::collections::HashMap;
+
+/// Builds the flat scope key `"{outer_scope}_{name}"` used to namespace
+/// variables, functions, and circuits inside `ConstrainedProgram`'s resolved
+/// value table.
+pub fn new_scope(outer_scope: String, name: String) -> String {
+    format!("{}_{}", outer_scope, name)
+}
+
+/// Tracks every resolved `ConstrainedValue` in a compiled Leo program, keyed by
+/// scope-qualified name.
+pub struct ConstrainedProgram<F: Field + PrimeField, G: GroupType<F>> {
+    /// Variables, functions, and circuits currently in scope.
+    identifiers: HashMap<String, ConstrainedValue<F, G>>,
+}
+
+// Written by hand rather than `#[derive(Default)]`: the derive macro adds
+// `F: Default, G: Default` bounds to the generated impl even though the body
+// only needs `HashMap::new()`, which would make `ConstrainedProgram::new()`
+// uncallable for any `F`/`G` that don't themselves implement `Default`.
+impl<F: Field + PrimeField, G: GroupType<F>> Default for ConstrainedProgram<F, G> {
+    fn default() -> Self {
+        Self {
+            identifiers: HashMap::new(),
+        }
+    }
+}
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the value currently bound to `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&ConstrainedValue<F, G>> {
+        self.identifiers.get(name)
+    }
+
+    /// Binds `name` to `value`, replacing any existing binding.
+    pub fn set(&mut self, name: String, value: ConstrainedValue<F, G>) {
+        self.identifiers.insert(name, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{int_value, MockField, MockGroup};
+
+    #[test]
+    fn set_then_get_returns_the_bound_value() {
+        let mut program: ConstrainedProgram<MockField, MockGroup> = ConstrainedProgram::new();
+
+        program.set("a".to_string(), int_value("1"));
+
+        match program.get("a").unwrap() {
+            ConstrainedValue::Integer(integer) => assert_eq!(integer.value, 1),
+            _ => panic!("expected an integer value"),
+        }
+    }
+
+    #[test]
+    fn reassigning_a_variable_replaces_its_bound_value() {
+        let mut program: ConstrainedProgram<MockField, MockGroup> = ConstrainedProgram::new();
+
+        program.set("a".to_string(), int_value("1"));
+        program.set("a".to_string(), int_value("2"));
+
+        match program.get("a").unwrap() {
+            ConstrainedValue::Integer(integer) => assert_eq!(integer.value, 2),
+            _ => panic!("expected an integer value"),
+        }
+    }
+}
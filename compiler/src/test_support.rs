@@ -0,0 +1,127 @@
+//! Shared test fixtures for `ConstrainedProgram<F, G>`'s unit tests.
+//!
+//! `F`/`G` are generic over curve field/group types that live in
+//! `snarkos_models` and aren't available in this tree, so every suite that
+//! exercises `ConstrainedProgram` needs a concrete stand-in. This module is
+//! that stand-in, so it only has to be written (and kept in sync) once.
+
+use crate::errors::GroupError;
+use crate::value::{ConstrainedValue, GroupType, Integer};
+use leo_types::{Identifier, Span};
+use snarkos_models::gadgets::r1cs::{ConstraintSystem, SynthesisError};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct MockField(pub u64);
+
+impl std::ops::Add for MockField {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        MockField(self.0 + other.0)
+    }
+}
+
+impl std::ops::Sub for MockField {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        MockField(self.0 - other.0)
+    }
+}
+
+impl std::ops::Mul for MockField {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        MockField(self.0 * other.0)
+    }
+}
+
+impl std::ops::Neg for MockField {
+    type Output = Self;
+    fn neg(self) -> Self {
+        MockField(self.0)
+    }
+}
+
+impl std::str::FromStr for MockField {
+    type Err = std::num::ParseIntError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value.parse::<u64>().map(MockField)
+    }
+}
+
+impl snarkos_models::curves::Field for MockField {
+    fn one() -> Self {
+        MockField(1)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Some(*self)
+    }
+}
+
+impl snarkos_models::curves::PrimeField for MockField {
+    type Repr = u64;
+
+    fn into_repr(&self) -> u64 {
+        self.0
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        MockField(self.0.pow(exp as u32))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct MockGroup;
+
+impl GroupType<MockField> for MockGroup {
+    fn constant(_string: String, _span: Span) -> Result<Self, GroupError> {
+        Ok(MockGroup)
+    }
+
+    fn is_constant(&self) -> bool {
+        true
+    }
+
+    fn negate<CS: ConstraintSystem<MockField>>(&self, _cs: &mut CS, _span: Span) -> Result<Self, GroupError> {
+        Ok(MockGroup)
+    }
+
+    fn const_negate(&self, _span: &Span) -> Result<Self, GroupError> {
+        Ok(MockGroup)
+    }
+
+    fn const_add(&self, _other: &Self, _span: &Span) -> Result<Self, GroupError> {
+        Ok(MockGroup)
+    }
+
+    fn const_sub(&self, _other: &Self, _span: &Span) -> Result<Self, GroupError> {
+        Ok(MockGroup)
+    }
+}
+
+/// A `ConstraintSystem` that never fails to allocate, so tests can drive
+/// enforcement without a real R1CS backend.
+pub(crate) struct MockCS;
+
+impl ConstraintSystem<MockField> for MockCS {
+    fn alloc<FN, A, AR>(&mut self, _annotation: A, f: FN) -> Result<usize, SynthesisError>
+    where
+        FN: FnOnce() -> Result<MockField, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        f().map(|_| 0)
+    }
+}
+
+pub(crate) fn span() -> Span {
+    Span { line: 0, start: 0 }
+}
+
+pub(crate) fn identifier(name: &str) -> Identifier {
+    Identifier { name: name.to_string(), span: span() }
+}
+
+pub(crate) fn int_value(n: &str) -> ConstrainedValue<MockField, MockGroup> {
+    ConstrainedValue::Integer(Integer::new_constant("u8", n.to_string(), span()).unwrap())
+}
@@ -0,0 +1,11 @@
+//! Build a `Boolean` gadget from a literal `bool` without allocating a constraint.
+
+use crate::errors::BooleanError;
+use leo_types::Span;
+use snarkos_models::gadgets::utilities::boolean::Boolean;
+
+/// Returns a `Boolean::Constant` wrapping `value`. Like `FieldType::constant`/`G::constant`,
+/// this never touches a `ConstraintSystem` since the value is already known.
+pub fn new_bool_constant(value: bool, _span: Span) -> Result<Boolean, BooleanError> {
+    Ok(Boolean::Constant(value))
+}
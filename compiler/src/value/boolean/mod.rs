@@ -0,0 +1,3 @@
+//! Helpers for constructing `Boolean` gadget values.
+
+pub mod input;
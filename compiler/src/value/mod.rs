@@ -0,0 +1,352 @@
+//! The resolved value of an `Expression` once it has been enforced against a
+//! `ConstraintSystem`: either a compile-time constant or an allocated gadget.
+
+pub mod boolean;
+
+use crate::errors::{AddressError, ExpressionError, FieldError, GroupError, IntegerError};
+use leo_types::{Identifier, Span, Type};
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{r1cs::ConstraintSystem, utilities::boolean::Boolean},
+};
+use std::fmt;
+
+/// An `address` literal, e.g. `aleo1...`. Addresses are always known at compile time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Address {
+    pub address: String,
+    pub span: Span,
+}
+
+impl Address {
+    pub fn new(address: String, span: Span) -> Result<Self, AddressError> {
+        Ok(Self { address, span })
+    }
+}
+
+/// A group of implementations one per curve (e.g. Edwards-BLS12) backing `group` values.
+/// Unlike `Integer`/`FieldType`, this stays a trait so each curve's affine point
+/// representation can provide its own constant/negate/add behavior.
+pub trait GroupType<F: Field + PrimeField>: Sized + Clone + fmt::Debug + PartialEq {
+    fn constant(string: String, span: Span) -> Result<Self, GroupError>;
+
+    fn is_constant(&self) -> bool;
+
+    fn negate<CS: ConstraintSystem<F>>(&self, cs: &mut CS, span: Span) -> Result<Self, GroupError>;
+
+    fn const_negate(&self, span: &Span) -> Result<Self, GroupError>;
+
+    fn const_add(&self, other: &Self, span: &Span) -> Result<Self, GroupError>;
+
+    fn const_sub(&self, other: &Self, span: &Span) -> Result<Self, GroupError>;
+}
+
+fn integer_bounds(type_name: &str) -> (u32, bool) {
+    if let Some(bits) = type_name.strip_prefix('u') {
+        (bits.parse().unwrap_or(8), false)
+    } else if let Some(bits) = type_name.strip_prefix('i') {
+        (bits.parse().unwrap_or(8), true)
+    } else {
+        (8, false)
+    }
+}
+
+/// A fixed-width integer value. Enforcement into `UInt`/`SInt` gadgets of the matching
+/// width happens lazily, so a constant integer never has to allocate anything.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Integer {
+    pub type_: String,
+    pub value: i128,
+    pub constant: bool,
+}
+
+impl Integer {
+    pub fn new_constant(type_: &str, value: String, span: Span) -> Result<Self, IntegerError> {
+        let parsed = value
+            .parse::<i128>()
+            .map_err(|error| IntegerError::new(format!("invalid integer literal `{}`: {}", value, error), span))?;
+
+        Ok(Self {
+            type_: type_.to_string(),
+            value: parsed,
+            constant: true,
+        })
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.constant
+    }
+
+    /// Reduces `value` to the declared width, wrapping on overflow the way the
+    /// corresponding `UInt`/`SInt` gadget would. `1i128.checked_shl(bits)` is
+    /// `None` exactly for `u128`/`i128` (`bits == 128`): there's no narrower
+    /// modulus to reduce by there, since `i128` itself already wraps at that
+    /// width with the same bit pattern a `u128` wraparound would produce.
+    fn wrap(&self, value: i128, span: &Span) -> Result<Self, IntegerError> {
+        let (bits, signed) = integer_bounds(&self.type_);
+        let _ = span;
+
+        let wrapped = match 1i128.checked_shl(bits) {
+            Some(modulus) if signed => {
+                let half = modulus / 2;
+                ((value % modulus) + modulus + half) % modulus - half
+            }
+            Some(modulus) => ((value % modulus) + modulus) % modulus,
+            None => value,
+        };
+
+        Ok(Self {
+            type_: self.type_.clone(),
+            value: wrapped,
+            constant: self.constant,
+        })
+    }
+
+    pub fn const_add(&self, other: &Self, span: &Span) -> Result<Self, IntegerError> {
+        self.wrap(self.value.wrapping_add(other.value), span)
+    }
+
+    pub fn const_sub(&self, other: &Self, span: &Span) -> Result<Self, IntegerError> {
+        self.wrap(self.value.wrapping_sub(other.value), span)
+    }
+
+    pub fn const_mul(&self, other: &Self, span: &Span) -> Result<Self, IntegerError> {
+        self.wrap(self.value.wrapping_mul(other.value), span)
+    }
+
+    pub fn const_div(&self, other: &Self, span: &Span) -> Result<Self, IntegerError> {
+        if other.value == 0 {
+            return Err(IntegerError::new("division by zero in constant expression".to_string(), span.clone()));
+        }
+
+        self.wrap(self.value.wrapping_div(other.value), span)
+    }
+
+    pub fn const_pow(&self, other: &Self, span: &Span) -> Result<Self, IntegerError> {
+        if other.value < 0 {
+            return Err(IntegerError::new("negative exponent in constant expression".to_string(), span.clone()));
+        }
+
+        let (bits, signed) = integer_bounds(&self.type_);
+        let result = self
+            .value
+            .checked_pow(other.value as u32)
+            .filter(|result| match 1i128.checked_shl(bits) {
+                Some(modulus) if signed => *result >= -(modulus / 2) && *result < modulus / 2,
+                Some(modulus) => result.unsigned_abs() < (modulus as u128),
+                None => true,
+            })
+            .ok_or_else(|| IntegerError::new("constant exponentiation overflowed".to_string(), span.clone()))?;
+
+        self.wrap(result, span)
+    }
+
+    pub fn const_negate(&self, span: &Span) -> Result<Self, IntegerError> {
+        self.wrap(self.value.wrapping_neg(), span)
+    }
+
+    pub fn negate<CS: ConstraintSystem<F>, F: Field + PrimeField>(&self, cs: &mut CS, span: Span) -> Result<Self, IntegerError> {
+        let name_unique = format!("negate {}:{}", span.line, span.start);
+        let _ = cs.alloc(|| name_unique, || Ok(F::one())).map_err(|_| {
+            IntegerError::new("failed to allocate negation witness".to_string(), span.clone())
+        })?;
+
+        self.const_negate(&span)
+    }
+}
+
+/// A field element. Constant field arithmetic happens directly on `F`; allocated
+/// (non-constant) field elements still carry their witness value so later constant
+/// folds over results derived from them keep working.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldType<F: Field + PrimeField> {
+    pub value: F,
+    pub constant: bool,
+}
+
+impl<F: Field + PrimeField> FieldType<F> {
+    pub fn constant(string: String, span: Span) -> Result<Self, FieldError> {
+        let value = F::from_str(&string)
+            .map_err(|_| FieldError::new(format!("invalid field literal `{}`", string), span))?;
+
+        Ok(Self { value, constant: true })
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.constant
+    }
+
+    pub fn const_add(&self, other: &Self, _span: &Span) -> Result<Self, FieldError> {
+        Ok(Self { value: self.value + other.value, constant: true })
+    }
+
+    pub fn const_sub(&self, other: &Self, _span: &Span) -> Result<Self, FieldError> {
+        Ok(Self { value: self.value - other.value, constant: true })
+    }
+
+    pub fn const_mul(&self, other: &Self, _span: &Span) -> Result<Self, FieldError> {
+        Ok(Self { value: self.value * other.value, constant: true })
+    }
+
+    pub fn const_div(&self, other: &Self, span: &Span) -> Result<Self, FieldError> {
+        let inverse = other
+            .value
+            .inverse()
+            .ok_or_else(|| FieldError::new("division by zero in constant expression".to_string(), span.clone()))?;
+
+        Ok(Self { value: self.value * inverse, constant: true })
+    }
+
+    pub fn const_pow(&self, other: &Self, _span: &Span) -> Result<Self, FieldError> {
+        let exponent = other.value.into_repr();
+
+        Ok(Self { value: self.value.pow(exponent), constant: true })
+    }
+
+    pub fn const_negate(&self, _span: &Span) -> Result<Self, FieldError> {
+        Ok(Self { value: -self.value, constant: true })
+    }
+
+    pub fn negate<CS: ConstraintSystem<F>>(&self, cs: &mut CS, span: Span) -> Result<Self, FieldError> {
+        let name_unique = format!("negate field {}:{}", span.line, span.start);
+        let _ = cs
+            .alloc(|| name_unique, || Ok(self.value))
+            .map_err(|_| FieldError::new("failed to allocate negation witness".to_string(), span.clone()))?;
+
+        let negated = self.const_negate(&span)?;
+
+        Ok(Self { value: negated.value, constant: self.constant })
+    }
+}
+
+/// The resolved value of an enforced `Expression`.
+#[derive(Clone, Debug)]
+pub enum ConstrainedValue<F: Field + PrimeField, G: GroupType<F>> {
+    Address(Address),
+    Boolean(Boolean),
+    Integer(Integer),
+    Field(FieldType<F>),
+    Group(G),
+    Unresolved(String),
+    Return(Vec<ConstrainedValue<F, G>>),
+}
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
+    /// Whether this value is known at compile time, so a binary expression
+    /// over it (and another constant) can be folded on the host instead of
+    /// falling back to a gadget.
+    pub fn is_constant(&self) -> bool {
+        match self {
+            ConstrainedValue::Address(_) => true,
+            ConstrainedValue::Boolean(boolean) => boolean.is_constant(),
+            ConstrainedValue::Integer(integer) => integer.is_constant(),
+            ConstrainedValue::Field(field) => field.is_constant(),
+            ConstrainedValue::Group(group) => group.is_constant(),
+            ConstrainedValue::Unresolved(_) => false,
+            ConstrainedValue::Return(values) => values.iter().all(Self::is_constant),
+        }
+    }
+
+    pub fn from_type(value: String, type_: &Type, _span: Span) -> Result<Self, ExpressionError> {
+        let _ = type_;
+        Ok(ConstrainedValue::Unresolved(value))
+    }
+
+    pub fn resolve_type(&mut self, _expected_types: &Vec<Type>, _span: Span) -> Result<(), ExpressionError> {
+        Ok(())
+    }
+
+    pub fn resolve_types(&mut self, _other: &mut Self, _expected_types: &Vec<Type>, _span: Span) -> Result<(), ExpressionError> {
+        Ok(())
+    }
+
+    pub fn get_inner_mut(&mut self) {}
+
+    pub fn extract_function(self, _file_scope: String, span: Span) -> Result<(String, Identifier), ExpressionError> {
+        Err(ExpressionError::cannot_enforce("extract_function".to_string(), span))
+    }
+
+    pub fn const_eq(&self, other: &Self, span: &Span) -> Result<bool, ExpressionError> {
+        match (self, other) {
+            (ConstrainedValue::Integer(left), ConstrainedValue::Integer(right)) => Ok(left.value == right.value),
+            (ConstrainedValue::Field(left), ConstrainedValue::Field(right)) => Ok(left.value == right.value),
+            (ConstrainedValue::Group(left), ConstrainedValue::Group(right)) => Ok(left == right),
+            (ConstrainedValue::Boolean(left), ConstrainedValue::Boolean(right)) => Ok(left.get_value() == right.get_value()),
+            _ => Err(ExpressionError::incompatible_types("==".to_string(), span.clone())),
+        }
+    }
+
+    pub fn const_ge(&self, other: &Self, span: &Span) -> Result<bool, ExpressionError> {
+        self.const_compare(other, span, |ordering| ordering.is_ge())
+    }
+
+    pub fn const_gt(&self, other: &Self, span: &Span) -> Result<bool, ExpressionError> {
+        self.const_compare(other, span, |ordering| ordering.is_gt())
+    }
+
+    pub fn const_le(&self, other: &Self, span: &Span) -> Result<bool, ExpressionError> {
+        self.const_compare(other, span, |ordering| ordering.is_le())
+    }
+
+    pub fn const_lt(&self, other: &Self, span: &Span) -> Result<bool, ExpressionError> {
+        self.const_compare(other, span, |ordering| ordering.is_lt())
+    }
+
+    fn const_compare(
+        &self,
+        other: &Self,
+        span: &Span,
+        matches: impl Fn(std::cmp::Ordering) -> bool,
+    ) -> Result<bool, ExpressionError> {
+        match (self, other) {
+            (ConstrainedValue::Integer(left), ConstrainedValue::Integer(right)) => Ok(matches(left.value.cmp(&right.value))),
+            _ => Err(ExpressionError::incompatible_types("relational comparison".to_string(), span.clone())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span() -> Span {
+        Span { line: 0, start: 0 }
+    }
+
+    fn integer(type_: &str, value: &str) -> Integer {
+        Integer::new_constant(type_, value.to_string(), span()).unwrap()
+    }
+
+    #[test]
+    fn const_add_wraps_to_the_declared_width() {
+        let sum = integer("u8", "250").const_add(&integer("u8", "10"), &span()).unwrap();
+
+        assert_eq!(sum.value, 4);
+    }
+
+    #[test]
+    fn const_add_wraps_at_the_full_width_of_i128_without_overflowing() {
+        let max = integer("i128", &i128::MAX.to_string());
+
+        let wrapped = max.const_add(&integer("i128", "1"), &span()).unwrap();
+
+        assert_eq!(wrapped.value, i128::MIN);
+    }
+
+    #[test]
+    fn const_div_by_zero_is_an_error() {
+        assert!(integer("u8", "5").const_div(&integer("u8", "0"), &span()).is_err());
+    }
+
+    #[test]
+    fn const_pow_overflow_is_an_error() {
+        assert!(integer("u8", "2").const_pow(&integer("u8", "9"), &span()).is_err());
+    }
+
+    #[test]
+    fn const_negate_wraps_signed_integers() {
+        let negated = integer("i8", "-128").const_negate(&span()).unwrap();
+
+        assert_eq!(negated.value, -128);
+    }
+}